@@ -0,0 +1,83 @@
+//! Exact continuous distributions, keyed by a hash like the rest of `FroggyRand`.
+//!
+//! `gen_froggy` already gives an approximate normal via the Irwin-Hall distribution,
+//! these give exact samples from a few distributions that come up often in games:
+//! realistic damage rolls, event timings, and crowd counts.
+//! They mirror the sampling methods in rand's `distributions` module but stay
+//! stateless, so every draw is a pure function of the key `x`.
+
+use core::hash::Hash;
+use libm::{cosf, expf, logf, sqrtf};
+
+use crate::FroggyRand;
+
+impl FroggyRand {
+    /// Samples from a normal (Gaussian) distribution via the Box-Muller transform.
+    /// https://en.wikipedia.org/wiki/Box%E2%80%93Muller_transform
+    #[inline]
+    pub fn gen_normal<T : Hash>(&self, x : T, mean : f32, std_dev : f32) -> f32 {
+        // Clamp away from 0 so ln() stays finite.
+        let u1 = self.gen_unit((&x, 0)).max(f32::EPSILON);
+        let u2 = self.gen_unit((&x, 1));
+
+        let z = sqrtf(-2.0 * logf(u1)) * cosf(2.0 * core::f32::consts::PI * u2);
+
+        mean + std_dev * z
+    }
+
+    /// Samples from an exponential distribution via inverse-CDF sampling.
+    /// https://en.wikipedia.org/wiki/Exponential_distribution
+    #[inline]
+    pub fn gen_exponential<T : Hash>(&self, x : T, lambda : f32) -> f32 {
+        -logf(1.0 - self.gen_unit(x)) / lambda
+    }
+
+    /// Samples from a Poisson distribution using Knuth's algorithm.
+    /// https://en.wikipedia.org/wiki/Poisson_distribution#Generating_Poisson-distributed_random_variables
+    #[inline]
+    pub fn gen_poisson<T : Hash>(&self, x : T, lambda : f32) -> u64 {
+        let l = expf(-lambda);
+        let mut k : u64 = 0;
+        let mut p = 1.0;
+
+        loop {
+            k += 1;
+            p *= self.gen_unit((&x, k));
+            if p <= l {
+                break;
+            }
+        }
+
+        k - 1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn gen_normal_is_deterministic() {
+        let froggy_rand = FroggyRand::new(100);
+        let val0 = froggy_rand.gen_normal(("normal", 0), 0.0, 1.0);
+        let val1 = froggy_rand.gen_normal(("normal", 0), 0.0, 1.0);
+        assert_eq!(val0, val1);
+    }
+
+    #[test]
+    fn gen_exponential_is_non_negative() {
+        let froggy_rand = FroggyRand::new(100);
+        for i in 0..50 {
+            let val = froggy_rand.gen_exponential(("exp", i), 1.5);
+            assert!(val >= 0.0);
+        }
+    }
+
+    #[test]
+    fn gen_poisson_is_deterministic() {
+        let froggy_rand = FroggyRand::new(100);
+        let val0 = froggy_rand.gen_poisson(("poisson", 0), 4.0);
+        let val1 = froggy_rand.gen_poisson(("poisson", 0), 4.0);
+        assert_eq!(val0, val1);
+    }
+}
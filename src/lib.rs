@@ -79,10 +79,21 @@
 
 #![no_std]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 use core::hash::{Hash, Hasher};
 use core::num::Wrapping;
+use libm::sqrtf;
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
 
 mod hasher;
+mod dist;
+mod iter;
+
+pub use iter::{FroggyIter, FroggyUnitIter, FroggyRangeIter};
 
 #[derive(Debug, Copy, Clone)]
 pub struct FroggyRand {
@@ -150,6 +161,83 @@ impl FroggyRand {
         &choices[i]
     }
 
+    /// Like `choose` but biased towards elements with a higher weight.
+    /// `weights` must be the same length as `choices` and non-empty.
+    /// Falls back to a uniform `choose` if every weight is zero.
+    ///
+    /// This walks the cumulative weights with a single O(n) linear scan rather than
+    /// building a prefix-sum array and binary-searching it, since `weights` is a borrowed
+    /// slice we can't mutate in place and building a second array needs the `alloc` feature
+    /// (see `choose_multiple`). Fine for the loot/spawn table sizes this is built for; if you
+    /// need O(log n) lookups on a large table, presort by weight and sum prefixes yourself.
+    #[inline]
+    pub fn choose_weighted<'a, T : Hash, X>(&self, x : T, choices : &'a [X], weights : &[f32]) -> &'a X {
+        assert_eq!(choices.len(), weights.len(), "choose_weighted : choices and weights must be the same length");
+        assert!(!choices.is_empty(), "choose_weighted : choices must not be empty");
+
+        let total : f32 = weights.iter().sum();
+        if total <= 0.0 {
+            return self.choose(x, choices);
+        }
+
+        let r = self.gen_unit(x) * total;
+
+        let mut cumulative = 0.0;
+        for (i, w) in weights.iter().enumerate() {
+            cumulative += w;
+            if r < cumulative {
+                return &choices[i];
+            }
+        }
+
+        // Floating point rounding can leave us just short of `total`, fall back to the last element.
+        &choices[choices.len() - 1]
+    }
+
+    /// Like `choose_weighted` but takes a closure to compute the weight of each element,
+    /// so callers don't need to build a parallel weights slice.
+    #[inline]
+    pub fn choose_weighted_with<'a, T : Hash, X>(&self, x : T, choices : &'a [X], weight : impl Fn(&X) -> f32) -> &'a X {
+        assert!(!choices.is_empty(), "choose_weighted_with : choices must not be empty");
+
+        let total : f32 = choices.iter().map(&weight).sum();
+        if total <= 0.0 {
+            return self.choose(x, choices);
+        }
+
+        let r = self.gen_unit(x) * total;
+
+        let mut cumulative = 0.0;
+        for (i, c) in choices.iter().enumerate() {
+            cumulative += weight(c);
+            if r < cumulative {
+                return &choices[i];
+            }
+        }
+
+        &choices[choices.len() - 1]
+    }
+
+    /// Stateless reservoir sampling (Algorithm R): picks `amount` distinct elements of `choices`
+    /// in a single O(n) pass. If `amount >= choices.len()` every element is returned, in order.
+    #[cfg(feature = "alloc")]
+    pub fn choose_multiple<'a, T : Hash, X>(&self, x : T, choices : &'a [X], amount : usize) -> Vec<&'a X> {
+        if amount >= choices.len() {
+            return choices.iter().collect();
+        }
+
+        let mut result : Vec<&'a X> = choices[..amount].iter().collect();
+
+        for i in amount..choices.len() {
+            let j = self.gen_usize_range((&x, i), 0, i);
+            if j < amount {
+                result[j] = &choices[i];
+            }
+        }
+
+        result
+    }
+
     /// I dont know what a statistic is
     /// Approx normal dist https://en.wikipedia.org/wiki/Irwin%E2%80%93Hall_distribution
     #[inline]
@@ -165,10 +253,74 @@ impl FroggyRand {
         sum
     }
 
+    /// Unbiased rejection sampling into `[0, range)`, using Lemire's method.
+    /// See https://lemire.me/blog/2019/06/06/nearly-divisionless-random-integer-generation-on-various-systems/
+    #[inline]
+    fn lemire_bounded<T : Hash>(&self, x : T, range : u64) -> u64 {
+        if range == 0 {
+            return 0;
+        }
+
+        let threshold = range.wrapping_neg() % range;
+        let mut attempt : u64 = 0;
+
+        loop {
+            let r = self.gen((&x, attempt));
+            let m = (r as u128) * (range as u128);
+            let lo = m as u64;
+
+            if lo >= threshold {
+                return (m >> 64) as u64;
+            }
+
+            attempt += 1;
+        }
+    }
+
+    /// Should be uniform in [min, max]
     #[inline]
     pub fn gen_usize_range<T : Hash>(&self, x : T, min : usize, max : usize) -> usize {
-        let range = 1 + max - min;
-        min + ((self.gen(x) as usize) % range)
+        // The full usize range is 2^64 wide on 64-bit platforms, which doesn't fit in a u64,
+        // so reinterpret the raw hash output directly instead of routing it through lemire_bounded.
+        if min == 0 && max == usize::MAX {
+            return self.gen(x) as usize;
+        }
+
+        // Do the arithmetic in u128 so `1 + max - min` and adding the offset to `min` can't overflow.
+        let range = (1 + max as u128 - min as u128) as u64;
+        let value = self.lemire_bounded(x, range) as u128;
+        (min as u128 + value) as usize
+    }
+
+    /// Should be uniform in [min, max]
+    #[inline]
+    pub fn gen_u32_range<T : Hash>(&self, x : T, min : u32, max : u32) -> u32 {
+        let range = 1 + max as u64 - min as u64;
+        min + self.lemire_bounded(x, range) as u32
+    }
+
+    /// Should be uniform in [min, max]
+    #[inline]
+    pub fn gen_i32_range<T : Hash>(&self, x : T, min : i32, max : i32) -> i32 {
+        // Do the arithmetic in i64 so adding the offset to `min` can't overflow i32.
+        let range = (1 + max as i64 - min as i64) as u64;
+        let value = self.lemire_bounded(x, range) as i64;
+        (min as i64 + value) as i32
+    }
+
+    /// Should be uniform in [min, max]
+    #[inline]
+    pub fn gen_i64_range<T : Hash>(&self, x : T, min : i64, max : i64) -> i64 {
+        // The full i64 range is 2^64 wide, which doesn't fit in a u64, so reinterpret
+        // the raw hash output directly instead of routing it through lemire_bounded.
+        if min == i64::MIN && max == i64::MAX {
+            return self.gen(x) as i64;
+        }
+
+        // Do the arithmetic in i128 so adding the offset to `min` can't overflow i64.
+        let range = (1 + max as i128 - min as i128) as u64;
+        let value = self.lemire_bounded(x, range) as i128;
+        (min as i128 + value) as i64
     }
 
     #[inline]
@@ -181,10 +333,61 @@ impl FroggyRand {
         }
     }
 
+    /// Should be uniform in [0, 255]
+    #[inline]
+    pub fn gen_u8<T : Hash>(&self, x : T) -> u8 {
+        self.lemire_bounded(x, 256) as u8
+    }
+
+    /// Should be uniform in [-128, 127]
+    #[inline]
+    pub fn gen_i8<T : Hash>(&self, x : T) -> i8 {
+        (self.lemire_bounded(x, 256) as i32 - 128) as i8
+    }
+
     /// Should be uniform in [0, 255]
     #[inline]
     pub fn gen_byte<T : Hash>(&self, x : T) -> u8 {
-        (self.gen(x) % 255) as u8
+        self.gen_u8(x)
+    }
+
+    /// Returns a uniformly random point on the unit circle, via rejection sampling.
+    /// https://en.wikipedia.org/wiki/Circle#Generating_random_points
+    #[inline]
+    pub fn gen_unit_circle<T : Hash>(&self, x : T) -> [f32; 2] {
+        let mut i : u64 = 0;
+
+        loop {
+            let a = self.gen_range((&x, i, 0), -1.0, 1.0);
+            let b = self.gen_range((&x, i, 1), -1.0, 1.0);
+            let s = a * a + b * b;
+
+            if s > 0.0 && s <= 1.0 {
+                return [(a * a - b * b) / s, (2.0 * a * b) / s];
+            }
+
+            i += 1;
+        }
+    }
+
+    /// Returns a uniformly random point on the unit sphere, via Marsaglia's method.
+    /// https://en.wikipedia.org/wiki/Sphere_point_picking#Uniform_distribution_on_the_sphere
+    #[inline]
+    pub fn gen_unit_sphere<T : Hash>(&self, x : T) -> [f32; 3] {
+        let mut i : u64 = 0;
+
+        loop {
+            let a = self.gen_range((&x, i, 0), -1.0, 1.0);
+            let b = self.gen_range((&x, i, 1), -1.0, 1.0);
+            let s = a * a + b * b;
+
+            if s < 1.0 {
+                let r = sqrtf(1.0 - s);
+                return [2.0 * a * r, 2.0 * b * r, 1.0 - 2.0 * s];
+            }
+
+            i += 1;
+        }
     }
 
     /// More performant gen() if the only control parameter you need is a single int.
@@ -238,4 +441,165 @@ mod tests {
         assert_ne!(val3, val1);
         assert_ne!(val3, val2);
     }
+
+    #[test]
+    fn choose_weighted_picks_only_nonzero_weight() {
+        let froggy_rand = FroggyRand::new(100);
+        let choices = [1, 2, 3];
+        let weights = [0.0, 1.0, 0.0];
+
+        for i in 0..20 {
+            let chosen = froggy_rand.choose_weighted(("weighted", i), &choices, &weights);
+            assert_eq!(*chosen, 2);
+        }
+    }
+
+    #[test]
+    fn choose_weighted_all_zero_falls_back_to_uniform() {
+        let froggy_rand = FroggyRand::new(100);
+        let choices = [1, 2, 3];
+        let weights = [0.0, 0.0, 0.0];
+
+        let chosen = froggy_rand.choose_weighted(("weighted_zero", 0), &choices, &weights);
+        assert!(choices.contains(chosen));
+    }
+
+    #[test]
+    fn gen_byte_can_reach_255() {
+        let froggy_rand = FroggyRand::new(100);
+        let hit_255 = (0..10_000).any(|i| froggy_rand.gen_byte(("byte", i)) == 255);
+        assert!(hit_255);
+    }
+
+    #[test]
+    fn gen_i8_is_in_range() {
+        let froggy_rand = FroggyRand::new(100);
+        let mut saw_negative = false;
+        let mut saw_positive = false;
+
+        for i in 0..1_000 {
+            let val = froggy_rand.gen_i8(("i8", i));
+            assert!(val >= i8::MIN && val <= i8::MAX);
+            saw_negative |= val < 0;
+            saw_positive |= val > 0;
+        }
+
+        assert!(saw_negative);
+        assert!(saw_positive);
+    }
+
+    #[test]
+    fn gen_i32_range_is_in_bounds() {
+        let froggy_rand = FroggyRand::new(100);
+        for i in 0..1_000 {
+            let val = froggy_rand.gen_i32_range(("i32_range", i), -10, 10);
+            assert!(val >= -10 && val <= 10);
+        }
+    }
+
+    #[test]
+    fn gen_i32_range_handles_wide_range_without_overflow() {
+        let froggy_rand = FroggyRand::new(100);
+        for i in 0..1_000 {
+            let val = froggy_rand.gen_i32_range(("i32_range_wide", i), -2_000_000_000, 2_000_000_000);
+            assert!(val >= -2_000_000_000 && val <= 2_000_000_000);
+        }
+    }
+
+    #[test]
+    fn gen_i64_range_handles_full_width_without_panicking() {
+        let froggy_rand = FroggyRand::new(100);
+        let mut saw_negative = false;
+        let mut saw_positive = false;
+
+        for i in 0..1_000 {
+            let val = froggy_rand.gen_i64_range(("i64_full", i), i64::MIN, i64::MAX);
+            saw_negative |= val < 0;
+            saw_positive |= val > 0;
+        }
+
+        assert!(saw_negative);
+        assert!(saw_positive);
+    }
+
+    #[test]
+    fn gen_usize_range_handles_full_width_without_panicking() {
+        let froggy_rand = FroggyRand::new(100);
+        let mut saw_small = false;
+        let mut saw_large = false;
+
+        for i in 0..1_000 {
+            let val = froggy_rand.gen_usize_range(("usize_full", i), 0, usize::MAX);
+            saw_small |= val < usize::MAX / 2;
+            saw_large |= val >= usize::MAX / 2;
+        }
+
+        assert!(saw_small);
+        assert!(saw_large);
+    }
+
+    #[test]
+    fn gen_u32_range_is_in_bounds() {
+        let froggy_rand = FroggyRand::new(100);
+        for i in 0..1_000 {
+            let val = froggy_rand.gen_u32_range(("u32_range", i), 10, 20);
+            assert!(val >= 10 && val <= 20);
+        }
+    }
+
+    #[test]
+    fn gen_u32_range_handles_wide_range_without_overflow() {
+        let froggy_rand = FroggyRand::new(100);
+        for i in 0..1_000 {
+            let val = froggy_rand.gen_u32_range(("u32_range_wide", i), 0, u32::MAX);
+            assert!(val <= u32::MAX);
+        }
+    }
+
+    #[test]
+    fn gen_unit_circle_is_on_unit_circle() {
+        let froggy_rand = FroggyRand::new(100);
+        for i in 0..100 {
+            let [x, y] = froggy_rand.gen_unit_circle(("circle", i));
+            let len_sq = x * x + y * y;
+            assert!((len_sq - 1.0).abs() < 0.001);
+        }
+    }
+
+    #[test]
+    fn gen_unit_sphere_is_on_unit_sphere() {
+        let froggy_rand = FroggyRand::new(100);
+        for i in 0..100 {
+            let [x, y, z] = froggy_rand.gen_unit_sphere(("sphere", i));
+            let len_sq = x * x + y * y + z * z;
+            assert!((len_sq - 1.0).abs() < 0.001);
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn choose_multiple_is_deterministic_and_distinct() {
+        let froggy_rand = FroggyRand::new(100);
+        let choices = [1, 2, 3, 4, 5, 6, 7, 8];
+
+        let a = froggy_rand.choose_multiple("multi", &choices, 3);
+        let b = froggy_rand.choose_multiple("multi", &choices, 3);
+        assert_eq!(a, b);
+        assert_eq!(a.len(), 3);
+
+        let mut sorted = a.clone();
+        sorted.sort();
+        sorted.dedup();
+        assert_eq!(sorted.len(), 3);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn choose_multiple_amount_over_len_returns_all_in_order() {
+        let froggy_rand = FroggyRand::new(100);
+        let choices = [1, 2, 3];
+
+        let result = froggy_rand.choose_multiple("multi_all", &choices, 10);
+        assert_eq!(result, [&1, &2, &3]);
+    }
 }
\ No newline at end of file